@@ -1,5 +1,6 @@
-use types::{Geometry, Point, LineString};
+use types::{Geometry, GeometryCollection, Line, Point, LineString};
 use num_traits::Float;
+use operation::line_intersection::{line_intersection, LineIntersection};
 
 pub trait Intersection<T, G> {
     fn intersection(&self, rhs: &G) -> Option<Geometry<T>> where T: Float;
@@ -102,10 +103,187 @@ impl<T> Intersection<T, Point<T>> for LineString<T>
     }
 }
 
+impl<T> LineString<T>
+    where T: Float {
+    /// For every segment of `self` that `point` lies on, returns the
+    /// segment's index, the point's normalized position `t` in `[0, 1]`
+    /// along that segment, and the point itself.
+    ///
+    /// Unlike `Intersection<T, Point<T>>::intersection`, which only reports
+    /// *that* a point lies on the LineString, this reports *where* -
+    /// letting callers order several intersections along a path or
+    /// interpolate attributes at the hit, ahead of a future `split`/`slice`
+    /// operation. `t` is the standard projection `(point - start) . (end -
+    /// start) / |end - start|^2`, which falls back to a plain y-projection
+    /// for vertical segments automatically, since `end - start` is then
+    /// `(0, dy)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let line_string = LineString(vec![
+    ///   Point::new(0.0, 0.0),
+    ///   Point::new(4.0, 0.0),
+    ///   Point::new(4.0, 4.0),
+    /// ]);
+    ///
+    /// let hit = Point::new(1.0, 0.0);
+    /// assert_eq!(vec![(0, 0.25, hit)], line_string.intersection_parametric(&hit));
+    ///
+    /// let off_line = Point::new(1.0, 1.0);
+    /// assert_eq!(Vec::<(usize, f64, Point<f64>)>::new(), line_string.intersection_parametric(&off_line));
+    /// ```
+    pub fn intersection_parametric(&self, point: &Point<T>) -> Vec<(usize, T, Point<T>)> {
+        let mut hits = Vec::new();
+
+        for (index, (start, end)) in self.0.iter().zip(self.0[1..].iter()).enumerate() {
+            let dx_point = point.x() - start.x();
+            let dy_point = point.y() - start.y();
+            let dx_line = end.x() - start.x();
+            let dy_line = end.y() - start.y();
+
+            let cross_product_magnitude = dx_point * dy_line - dy_point * dx_line;
+            if cross_product_magnitude != T::zero() {
+                continue;
+            }
+
+            let coord = if dx_line == T::zero() {
+                Point::y
+            } else {
+                Point::x
+            };
+
+            let (lower_bound, upper_bound) = if coord(start) < coord(end) {
+                (coord(start), coord(end))
+            } else {
+                (coord(end), coord(start))
+            };
+
+            if coord(point) < lower_bound || coord(point) > upper_bound {
+                continue;
+            }
+
+            let length_squared = dx_line * dx_line + dy_line * dy_line;
+            let t = if length_squared == T::zero() {
+                T::zero()
+            } else {
+                ((dx_point * dx_line + dy_point * dy_line) / length_squared)
+                    .max(T::zero())
+                    .min(T::one())
+            };
+
+            hits.push((index, t, point.clone()));
+        }
+
+        hits
+    }
+
+    /// Like `Intersection<T, Point<T>>::intersection`, but tolerant of the
+    /// floating-point error that a point produced by prior arithmetic (e.g.
+    /// a previous `intersection` call) commonly carries: a point within
+    /// `epsilon` of a segment is treated as lying on it, rather than being
+    /// rejected for a microscopically nonzero cross product or a bound
+    /// check that misses by a hair.
+    ///
+    /// Concretely, the cross product is treated as zero when its magnitude
+    /// is at most `epsilon * segment_length` (a relative rather than
+    /// absolute tolerance, so it scales with the geometry), and the
+    /// segment's bounding interval is widened by `epsilon` on each side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let line_string = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]);
+    /// let almost_on_line = Point::new(0.5 + 1e-12, 0.5);
+    ///
+    /// assert_eq!(None, line_string.intersection(&almost_on_line));
+    /// assert!(line_string.intersection_within(&almost_on_line, 1e-6).is_some());
+    /// ```
+    pub fn intersection_within(&self, point: &Point<T>, epsilon: T) -> Option<Geometry<T>> {
+        for (start, end) in self.0.iter().zip(self.0[1..].iter()) {
+            let dx_point = point.x() - start.x();
+            let dy_point = point.y() - start.y();
+            let dx_line = end.x() - start.x();
+            let dy_line = end.y() - start.y();
+
+            let segment_length = (dx_line * dx_line + dy_line * dy_line).sqrt();
+            let cross_product_magnitude = dx_point * dy_line - dy_point * dx_line;
+
+            if cross_product_magnitude.abs() > epsilon * segment_length {
+                continue;
+            }
+
+            let coord = if dx_line == T::zero() {
+                Point::y
+            } else {
+                Point::x
+            };
+
+            let (lower_bound, upper_bound) = if coord(start) < coord(end) {
+                (coord(start), coord(end))
+            } else {
+                (coord(end), coord(start))
+            };
+
+            if coord(point) >= lower_bound - epsilon && coord(point) <= upper_bound + epsilon {
+                return Some(Geometry::Point(point.clone()));
+            }
+        }
+        None
+    }
+
+    /// `intersection_within` with a default epsilon scaled to the magnitude
+    /// of `self`'s own coordinates, for callers who want a robust,
+    /// tolerance-aware query without picking an epsilon themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let line_string = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]);
+    /// let almost_on_line = Point::new(0.5 + 1e-12, 0.5);
+    ///
+    /// assert!(line_string.intersection_approximate(&almost_on_line).is_some());
+    /// ```
+    pub fn intersection_approximate(&self, point: &Point<T>) -> Option<Geometry<T>> {
+        self.intersection_within(point, self.default_epsilon())
+    }
+
+    /// A relative epsilon derived from the magnitude of `self`'s
+    /// coordinates, loose enough to absorb the rounding error a point
+    /// accumulates after a few arithmetic operations (e.g. an earlier
+    /// `intersection` call), not just a single rounding step.
+    ///
+    /// `T::epsilon()` itself is the tolerance for *one* operation - using it
+    /// directly rejects points that are many float operations removed from
+    /// exact, which is the common case this method exists for. Its square
+    /// root is the standard compromise: several orders of magnitude looser
+    /// than one rounding step, but still tight enough not to treat
+    /// genuinely distinct points as coincident.
+    fn default_epsilon(&self) -> T {
+        let max_magnitude = self.0.iter().fold(T::zero(), |acc, point| {
+            acc.max(point.x().abs()).max(point.y().abs())
+        });
+
+        T::epsilon().sqrt() * (T::one() + max_magnitude)
+    }
+}
+
 impl<T> Intersection<T, LineString<T>> for LineString<T>
     where T: Float {
     /// Returns any overlapping line segements and intersecting points
     ///
+    /// A pair of polylines may cross at several disjoint points and overlap
+    /// along several disjoint segments all at once, so when more than one
+    /// intersection is found the result is a `Geometry::GeometryCollection`
+    /// mixing `Geometry::Point`s and `Geometry::LineString`s. When there's
+    /// exactly one intersection, it's returned unwrapped, as before.
+    ///
     /// # Examples
     ///
     /// ```
@@ -130,12 +308,90 @@ impl<T> Intersection<T, LineString<T>> for LineString<T>
     /// assert_eq!(Some(Geometry::LineString(line_string.clone())), line_string.intersection(&same_line_string));
     /// assert_eq!(None, line_string.intersection(&far_away_line_string));
     /// ```
+    ///
+    /// An "L" crossing another polyline that both touches it and runs
+    /// alongside it for a while returns a `GeometryCollection` of the two
+    /// touch points plus the one shared segment:
+    ///
+    /// ```
+    /// use geo::{Geometry, GeometryCollection, Point, LineString};
+    /// use geo::operation::intersection::Intersection;
+    ///
+    /// let l_shape = LineString(vec![
+    ///   Point::new(0.0, 0.0),
+    ///   Point::new(4.0, 0.0),
+    ///   Point::new(4.0, 4.0),
+    /// ]);
+    ///
+    /// let other = LineString(vec![
+    ///   Point::new(4.0, -2.0),
+    ///   Point::new(4.0, 2.0),
+    ///   Point::new(2.0, 2.0),
+    ///   Point::new(2.0, 6.0),
+    /// ]);
+    ///
+    /// let expected = Geometry::GeometryCollection(GeometryCollection(vec![
+    ///   Geometry::Point(Point::new(4.0, 0.0)),
+    ///   Geometry::LineString(LineString(vec![Point::new(4.0, 0.0), Point::new(4.0, 2.0)])),
+    ///   Geometry::Point(Point::new(4.0, 2.0)),
+    /// ]));
+    ///
+    /// assert_eq!(Some(expected), l_shape.intersection(&other));
+    /// ```
     fn intersection(&self, other_line_string: &LineString<T>) -> Option<Geometry<T>> {
-        // TODO actually implement this method
-        if self.0.eq(&other_line_string.0) {
-            return Some(Geometry::LineString(self.clone()));
-        } else {
-            return None
+        let mut geometries: Vec<Geometry<T>> = Vec::new();
+
+        for (a_start, a_end) in self.0.iter().zip(self.0[1..].iter()) {
+            let a = Line::new(a_start.clone(), a_end.clone());
+
+            for (b_start, b_end) in other_line_string.0.iter().zip(other_line_string.0[1..].iter()) {
+                let b = Line::new(b_start.clone(), b_end.clone());
+
+                if let Some(geometry) = line_intersection(&a, &b).map(geometry_from_line_intersection) {
+                    push_unique(&mut geometries, geometry);
+                }
+            }
+        }
+
+        match geometries.len() {
+            0 => None,
+            1 => Some(geometries.remove(0)),
+            _ => Some(Geometry::GeometryCollection(GeometryCollection(geometries))),
+        }
+    }
+}
+
+/// Converts a `LineIntersection` into the `Point`/`LineString` geometry
+/// reported by `LineString::intersection`, discarding the `is_proper` flag
+/// that callers of this coarser API don't need.
+fn geometry_from_line_intersection<T>(intersection: LineIntersection<T>) -> Geometry<T>
+    where T: Float {
+    match intersection {
+        LineIntersection::SinglePoint { intersection, .. } => Geometry::Point(intersection),
+        LineIntersection::Collinear { intersection } => {
+            Geometry::LineString(LineString(vec![intersection.start(), intersection.end()]))
+        }
+    }
+}
+
+/// Pushes `geometry` onto `geometries` unless it's a `Point` that's already
+/// present, which happens when two adjacent segments both report the shared
+/// vertex between them as an intersection.
+fn push_unique<T>(geometries: &mut Vec<Geometry<T>>, geometry: Geometry<T>)
+    where T: Float {
+    if let Geometry::Point(ref point) = geometry {
+        let already_present = geometries.iter().any(|existing| {
+            if let Geometry::Point(ref existing_point) = *existing {
+                existing_point.eq(point)
+            } else {
+                false
+            }
+        });
+
+        if already_present {
+            return;
         }
     }
+
+    geometries.push(geometry);
 }
@@ -0,0 +1,100 @@
+use types::{Line, Point};
+use num_traits::Float;
+
+/// The result of intersecting two line segments: either a single point, or,
+/// when the segments are collinear and overlap, the shared sub-segment.
+#[derive(PartialEq, Debug, Clone)]
+pub enum LineIntersection<T>
+    where T: Float {
+    SinglePoint {
+        intersection: Point<T>,
+        is_proper: bool,
+    },
+    Collinear {
+        intersection: Line<T>,
+    },
+}
+
+/// Intersects two line segments `a` and `b`.
+///
+/// Segments are parameterized as `a.start + t*r` and `b.start + u*s` for
+/// `t, u` in `[0, 1]`, where `r` and `s` are the segments' direction
+/// vectors. When `r x s != 0` the segments are non-parallel and `t`/`u` are
+/// solved for directly via the 2D cross product; a hit is `is_proper` when
+/// both parameters fall strictly inside `(0, 1)`, i.e. the segments cross
+/// away from either's endpoints. When `r x s == 0` the segments are
+/// parallel: if they're also collinear, both are projected onto `r` and the
+/// resulting parameter intervals are intersected to find the overlap.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Line, Point};
+/// use geo::operation::line_intersection::{line_intersection, LineIntersection};
+///
+/// let a = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 4.0));
+/// let b = Line::new(Point::new(0.0, 4.0), Point::new(4.0, 0.0));
+///
+/// assert_eq!(
+///     Some(LineIntersection::SinglePoint { intersection: Point::new(2.0, 2.0), is_proper: true }),
+///     line_intersection(&a, &b)
+/// );
+/// ```
+pub fn line_intersection<T>(a: &Line<T>, b: &Line<T>) -> Option<LineIntersection<T>>
+    where T: Float {
+    let p1 = a.start();
+    let p2 = a.end();
+    let q1 = b.start();
+    let q2 = b.end();
+
+    let r = (p2.x() - p1.x(), p2.y() - p1.y());
+    let s = (q2.x() - q1.x(), q2.y() - q1.y());
+    let rxs = r.0 * s.1 - r.1 * s.0;
+    let qmp = (q1.x() - p1.x(), q1.y() - p1.y());
+    let qmp_x_r = qmp.0 * r.1 - qmp.1 * r.0;
+
+    if rxs == T::zero() {
+        if qmp_x_r != T::zero() {
+            // parallel, and not collinear
+            return None;
+        }
+
+        // collinear: project both segments onto r and intersect the
+        // resulting parameter intervals
+        let r_dot_r = r.0 * r.0 + r.1 * r.1;
+        if r_dot_r == T::zero() {
+            return None;
+        }
+
+        let t0 = (qmp.0 * r.0 + qmp.1 * r.1) / r_dot_r;
+        let t1 = t0 + (s.0 * r.0 + s.1 * r.1) / r_dot_r;
+        let (lower, upper) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        let lower = lower.max(T::zero());
+        let upper = upper.min(T::one());
+
+        if lower > upper {
+            return None;
+        }
+
+        let start = Point::new(p1.x() + r.0 * lower, p1.y() + r.1 * lower);
+        let end = Point::new(p1.x() + r.0 * upper, p1.y() + r.1 * upper);
+
+        return if lower == upper {
+            Some(LineIntersection::SinglePoint { intersection: start, is_proper: false })
+        } else {
+            Some(LineIntersection::Collinear { intersection: Line::new(start, end) })
+        };
+    }
+
+    let qmp_x_s = qmp.0 * s.1 - qmp.1 * s.0;
+    let t = qmp_x_s / rxs;
+    let u = qmp_x_r / rxs;
+
+    if t >= T::zero() && t <= T::one() && u >= T::zero() && u <= T::one() {
+        let is_proper = t > T::zero() && t < T::one() && u > T::zero() && u < T::one();
+        let intersection = Point::new(p1.x() + r.0 * t, p1.y() + r.1 * t);
+        Some(LineIntersection::SinglePoint { intersection, is_proper })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,4 @@
+pub mod intersection;
+pub mod line_intersection;
+pub mod sweep_line;
+pub mod ray;
@@ -0,0 +1,152 @@
+use types::{Geometry, LineString, Point, Rect};
+use num_traits::Float;
+use operation::intersection::Intersection;
+
+/// A ray: a half-infinite line starting at `origin` and extending forever in
+/// `direction`. Used for picking and visibility queries, where "where does
+/// this point in this direction first hit something" is the question, as
+/// opposed to the segment-to-segment queries `Intersection` otherwise deals
+/// with.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Ray<T>
+    where T: Float {
+    pub origin: Point<T>,
+    pub direction: Point<T>,
+}
+
+impl<T> Ray<T>
+    where T: Float {
+    pub fn new(origin: Point<T>, direction: Point<T>) -> Ray<T> {
+        Ray { origin, direction }
+    }
+}
+
+impl<T> Intersection<T, LineString<T>> for Ray<T>
+    where T: Float {
+    /// Returns the nearest point where the ray hits `line_string`, if any.
+    ///
+    /// Each segment is tested as in [`line_intersection`](../line_intersection/fn.line_intersection.html),
+    /// except the ray's parameter is only accepted for `t >= 0` rather than
+    /// `t` in `[0, 1]`, since a ray has no far endpoint. Unlike
+    /// `line_intersection`, a segment parallel to the ray is never a hit,
+    /// even one the ray runs along.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Geometry, Point, LineString};
+    /// use geo::operation::intersection::Intersection;
+    /// use geo::operation::ray::Ray;
+    ///
+    /// let ray = Ray::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+    /// let line_string = LineString(vec![Point::new(2.0, -1.0), Point::new(2.0, 1.0)]);
+    ///
+    /// assert_eq!(Some(Geometry::Point(Point::new(2.0, 0.0))), ray.intersection(&line_string));
+    /// ```
+    fn intersection(&self, line_string: &LineString<T>) -> Option<Geometry<T>> {
+        let mut nearest: Option<(T, Point<T>)> = None;
+
+        for (start, end) in line_string.0.iter().zip(line_string.0[1..].iter()) {
+            if let Some((t, point)) = ray_segment_intersection(self, start, end) {
+                let is_nearer = match nearest {
+                    None => true,
+                    Some((nearest_t, _)) => t < nearest_t,
+                };
+
+                if is_nearer {
+                    nearest = Some((t, point));
+                }
+            }
+        }
+
+        nearest.map(|(_, point)| Geometry::Point(point))
+    }
+}
+
+impl<T> Intersection<T, Rect<T>> for Ray<T>
+    where T: Float {
+    /// Returns the point where the ray first enters `rect`, if it hits it at
+    /// all, via the slab method: the ray is clipped against each axis'
+    /// `[min, max]` slab in turn, narrowing `[t_min, t_max]` until either the
+    /// interval is empty (a miss) or every axis has been applied (a hit at
+    /// `t_min`, or at `t = 0` if the ray starts inside the rect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Geometry, Point, Rect};
+    /// use geo::operation::intersection::Intersection;
+    /// use geo::operation::ray::Ray;
+    ///
+    /// let ray = Ray::new(Point::new(-1.0, 0.5), Point::new(1.0, 0.0));
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    ///
+    /// assert_eq!(Some(Geometry::Point(Point::new(0.0, 0.5))), ray.intersection(&rect));
+    /// ```
+    fn intersection(&self, rect: &Rect<T>) -> Option<Geometry<T>> {
+        let mut t_min = T::zero();
+        let mut t_max = T::max_value();
+
+        let axes = [
+            (self.origin.x(), self.direction.x(), rect.min().x(), rect.max().x()),
+            (self.origin.y(), self.direction.y(), rect.min().y(), rect.max().y()),
+        ];
+
+        for &(origin, direction, min, max) in axes.iter() {
+            if direction == T::zero() {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            let (t1, t2) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+
+            t_min = t_min.max(t1);
+            t_max = t_max.min(t2);
+
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        Some(Geometry::Point(Point::new(
+            self.origin.x() + self.direction.x() * t_min,
+            self.origin.y() + self.direction.y() * t_min,
+        )))
+    }
+}
+
+/// Intersects `ray` against the segment from `start` to `end`, returning the
+/// ray parameter `t` alongside the hit point so callers can pick the
+/// nearest of several candidate segments.
+///
+/// This solves the same 2x2 cross-product system as
+/// [`line_intersection`](../line_intersection/fn.line_intersection.html),
+/// except only the segment's parameter `u` is bounded to `[0, 1]` - the
+/// ray's parameter `t` just needs `t >= 0`, since a ray has no far endpoint.
+fn ray_segment_intersection<T>(ray: &Ray<T>, start: &Point<T>, end: &Point<T>) -> Option<(T, Point<T>)>
+    where T: Float {
+    let r = (ray.direction.x(), ray.direction.y());
+    let s = (end.x() - start.x(), end.y() - start.y());
+    let rxs = r.0 * s.1 - r.1 * s.0;
+
+    if rxs == T::zero() {
+        // parallel (including collinear): treated as a miss, so a segment
+        // the ray runs along is not reported
+        return None;
+    }
+
+    let qmp = (start.x() - ray.origin.x(), start.y() - ray.origin.y());
+    let t = (qmp.0 * s.1 - qmp.1 * s.0) / rxs;
+    let u = (qmp.0 * r.1 - qmp.1 * r.0) / rxs;
+
+    if t >= T::zero() && u >= T::zero() && u <= T::one() {
+        let point = Point::new(ray.origin.x() + r.0 * t, ray.origin.y() + r.1 * t);
+        Some((t, point))
+    } else {
+        None
+    }
+}
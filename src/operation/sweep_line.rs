@@ -0,0 +1,395 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+use types::{Line, LineString, Point};
+use num_traits::Float;
+use operation::line_intersection::{line_intersection, LineIntersection};
+
+/// An (x, y) pair used as a priority-queue / ordering key.
+///
+/// `T: Float` has no total order (it admits NaN), so keys are compared with
+/// `partial_cmp`, which panics on NaN input rather than silently misordering
+/// the sweep.
+#[derive(PartialEq, Clone, Copy)]
+struct SweepKey<T>(T, T);
+
+impl<T: Float> Eq for SweepKey<T> {}
+
+impl<T: Float> Ord for SweepKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let x_ordering = self.0.partial_cmp(&other.0).expect("NaN coordinate in sweep-line input");
+        if x_ordering != Ordering::Equal {
+            return x_ordering;
+        }
+        self.1.partial_cmp(&other.1).expect("NaN coordinate in sweep-line input")
+    }
+}
+
+impl<T: Float> PartialOrd for SweepKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+enum EventKind {
+    Left(usize),
+    Crossing(usize, usize),
+    Right(usize),
+}
+
+impl EventKind {
+    /// Processing rank at a shared (x, y) key: `Left` must be handled
+    /// before `Right` so that two segments meeting exactly at a vertex are
+    /// both in the status at once - otherwise whichever of the outgoing
+    /// `Right` and the next segment's incoming `Left` the heap happens to
+    /// pop first would never see the other, and the shared vertex could go
+    /// undetected. `Crossing` sits in between: any crossing discovered
+    /// exactly at this point depends on both `Left`s already being in the
+    /// status, and must be recorded before the `Right` that retires one of
+    /// its segments.
+    fn rank(&self) -> u8 {
+        match *self {
+            EventKind::Left(_) => 0,
+            EventKind::Crossing(_, _) => 1,
+            EventKind::Right(_) => 2,
+        }
+    }
+}
+
+struct Event<T> {
+    key: SweepKey<T>,
+    kind: EventKind,
+}
+
+impl<T: Float> PartialEq for Event<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.kind.rank() == other.kind.rank()
+    }
+}
+
+impl<T: Float> Eq for Event<T> {}
+
+impl<T: Float> PartialOrd for Event<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Event<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap; reverse so the event with the smallest
+        // (x, y) key - the next one the sweep should reach - pops first.
+        // Ties at the same key are broken by `EventKind::rank` so `Left`,
+        // `Crossing` and `Right` events at one point process in a
+        // deterministic order instead of whatever the heap's internal
+        // layout happens to produce.
+        other.key.cmp(&self.key).then_with(|| other.kind.rank().cmp(&self.kind.rank()))
+    }
+}
+
+/// The y-coordinate a segment has at sweep position `x`, used to order the
+/// status structure. Vertical segments (which have no single y at a given
+/// x) are ordered by their lower endpoint.
+fn y_at_x<T: Float>(line: &Line<T>, x: T) -> T {
+    let (start, end) = (line.start(), line.end());
+
+    if start.x() == end.x() {
+        start.y().min(end.y())
+    } else {
+        let t = (x - start.x()) / (end.x() - start.x());
+        start.y() + t * (end.y() - start.y())
+    }
+}
+
+/// The status structure: segment indices currently crossing the sweep line,
+/// ordered by their y position at the current sweep x.
+///
+/// This plays the role of the balanced BST a textbook Bentley-Ottmann
+/// implementation keeps the status in; a `Vec` is used here for simplicity,
+/// trading the O(log n) neighbor lookups of a real order-statistic tree for
+/// a simpler O(n) linear scan.
+struct Status {
+    order: Vec<usize>,
+}
+
+impl Status {
+    fn new() -> Self {
+        Status { order: Vec::new() }
+    }
+
+    fn insert<T: Float>(&mut self, lines: &[Line<T>], x: T, segment: usize) -> usize {
+        let y = y_at_x(&lines[segment], x);
+        let index = self.order.iter().position(|&other| y_at_x(&lines[other], x) > y)
+            .unwrap_or(self.order.len());
+        self.order.insert(index, segment);
+        index
+    }
+
+    fn remove(&mut self, segment: usize) -> usize {
+        let index = self.order.iter().position(|&i| i == segment)
+            .unwrap_or_else(|| panic!("segment {} not in status", segment));
+        self.order.remove(index);
+        index
+    }
+
+    fn swap_neighbors(&mut self, a: usize, b: usize) {
+        let ia = self.order.iter().position(|&i| i == a).expect("segment in status");
+        let ib = self.order.iter().position(|&i| i == b).expect("segment in status");
+        self.order.swap(ia, ib);
+    }
+
+    fn above(&self, segment: usize) -> Option<usize> {
+        let index = self.order.iter().position(|&i| i == segment)?;
+        self.order.get(index + 1).cloned()
+    }
+
+    fn below(&self, segment: usize) -> Option<usize> {
+        let index = self.order.iter().position(|&i| i == segment)?;
+        if index == 0 {
+            None
+        } else {
+            self.order.get(index - 1).cloned()
+        }
+    }
+}
+
+/// Every pairwise intersection among `lines`, found with a Bentley-Ottmann
+/// sweep rather than the naive O(n*m) all-pairs scan.
+///
+/// Returns one entry per distinct intersection point, paired with the
+/// indices (into `lines`) of every segment that passes through it. A
+/// collinear overlap between two segments is reported as an intersection at
+/// both of the overlap's endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Line, Point};
+/// use geo::operation::sweep_line::intersections;
+///
+/// let lines = vec![
+///     Line::new(Point::new(0.0, 0.0), Point::new(4.0, 4.0)),
+///     Line::new(Point::new(0.0, 4.0), Point::new(4.0, 0.0)),
+/// ];
+///
+/// let found: Vec<_> = intersections(&lines).collect();
+/// assert_eq!(1, found.len());
+/// assert_eq!(Point::new(2.0, 2.0), found[0].0);
+/// assert_eq!(vec![0, 1], found[0].1);
+/// ```
+pub fn intersections<T>(lines: &[Line<T>]) -> ::std::vec::IntoIter<(Point<T>, Vec<usize>)>
+    where T: Float {
+    let mut queue: BinaryHeap<Event<T>> = BinaryHeap::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let (start, end) = (line.start(), line.end());
+        let (left, right) = if SweepKey(start.x(), start.y()).cmp(&SweepKey(end.x(), end.y())) == Ordering::Less {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        queue.push(Event { key: SweepKey(left.x(), left.y()), kind: EventKind::Left(i) });
+        queue.push(Event { key: SweepKey(right.x(), right.y()), kind: EventKind::Right(i) });
+    }
+
+    let mut status = Status::new();
+    let mut scheduled: HashSet<(usize, usize)> = HashSet::new();
+    let mut results: Vec<(Point<T>, Vec<usize>)> = Vec::new();
+
+    while let Some(event) = queue.pop() {
+        let sweep_x = event.key.0;
+
+        match event.kind {
+            EventKind::Left(i) => {
+                status.insert(lines, sweep_x, i);
+                if let Some(above) = status.above(i) {
+                    test_pair(lines, i, above, sweep_x, &mut queue, &mut scheduled, &mut results);
+                }
+                if let Some(below) = status.below(i) {
+                    test_pair(lines, i, below, sweep_x, &mut queue, &mut scheduled, &mut results);
+                }
+            }
+            EventKind::Right(i) => {
+                let above = status.above(i);
+                let below = status.below(i);
+                status.remove(i);
+                if let (Some(above), Some(below)) = (above, below) {
+                    test_pair(lines, above, below, sweep_x, &mut queue, &mut scheduled, &mut results);
+                }
+            }
+            EventKind::Crossing(a, b) => {
+                record(Point::new(event.key.0, event.key.1), a, b, &mut results);
+                status.swap_neighbors(a, b);
+
+                // `a < b` by index, not by position: find which one is now on top
+                let (lower, upper) = if status.above(a) == Some(b) { (a, b) } else { (b, a) };
+
+                if let Some(above) = status.above(upper) {
+                    test_pair(lines, upper, above, sweep_x, &mut queue, &mut scheduled, &mut results);
+                }
+                if let Some(below) = status.below(lower) {
+                    test_pair(lines, lower, below, sweep_x, &mut queue, &mut scheduled, &mut results);
+                }
+            }
+        }
+    }
+
+    results.into_iter()
+}
+
+/// Records that segments `a` and `b` meet at `point`, merging into an
+/// existing entry at the same location rather than duplicating it (this is
+/// how a shared vertex ends up listing every segment that touches it).
+fn record<T: Float>(point: Point<T>, a: usize, b: usize, results: &mut Vec<(Point<T>, Vec<usize>)>) {
+    for &mut (ref existing_point, ref mut indices) in results.iter_mut() {
+        if existing_point.eq(&point) {
+            if !indices.contains(&a) { indices.push(a); }
+            if !indices.contains(&b) { indices.push(b); }
+            return;
+        }
+    }
+    results.push((point, vec![a, b]));
+}
+
+/// Tests newly-adjacent segments `a` and `b` for an intersection: a crossing
+/// strictly to the right of the sweep is scheduled as a future event, while
+/// one at or behind the sweep (found when a segment is inserted or removed
+/// next to a segment it already meets) is recorded immediately.
+fn test_pair<T: Float>(
+    lines: &[Line<T>],
+    a: usize,
+    b: usize,
+    sweep_x: T,
+    queue: &mut BinaryHeap<Event<T>>,
+    scheduled: &mut HashSet<(usize, usize)>,
+    results: &mut Vec<(Point<T>, Vec<usize>)>,
+) {
+    let key = if a < b { (a, b) } else { (b, a) };
+
+    match line_intersection(&lines[a], &lines[b]) {
+        None => {}
+        Some(LineIntersection::SinglePoint { intersection, .. }) => {
+            if intersection.x() > sweep_x {
+                if scheduled.insert(key) {
+                    queue.push(Event {
+                        key: SweepKey(intersection.x(), intersection.y()),
+                        kind: EventKind::Crossing(key.0, key.1),
+                    });
+                }
+            } else {
+                record(intersection, a, b, results);
+            }
+        }
+        Some(LineIntersection::Collinear { intersection }) => {
+            record(intersection.start(), a, b, results);
+            record(intersection.end(), a, b, results);
+        }
+    }
+}
+
+/// Segments formed by consecutive points of `line_string`.
+fn segments<T>(line_string: &LineString<T>) -> Vec<Line<T>>
+    where T: Float {
+    line_string.0.iter().zip(line_string.0[1..].iter())
+        .map(|(start, end)| Line::new(start.clone(), end.clone()))
+        .collect()
+}
+
+/// Whether `line_string` crosses or touches itself anywhere beyond the
+/// ordinary vertices chaining one segment to the next.
+///
+/// Every consecutive pair of segments in a `LineString` shares an endpoint
+/// by construction, so a version of this check that counted those chain
+/// vertices as self-intersections would be true for essentially any
+/// `LineString` of two or more segments - not a useful predicate. This is
+/// equivalent to
+/// [`is_self_intersecting_exclusive`](fn.is_self_intersecting_exclusive.html);
+/// it exists as the plain, expected name for "does this polyline actually
+/// cross or overlap itself".
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Point, LineString};
+/// use geo::operation::sweep_line::is_self_intersecting;
+///
+/// let simple = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 0.0)]);
+/// assert!(!is_self_intersecting(&simple));
+///
+/// let figure_eight = LineString(vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(2.0, 2.0),
+///     Point::new(2.0, 0.0),
+///     Point::new(0.0, 2.0),
+/// ]);
+/// assert!(is_self_intersecting(&figure_eight));
+/// ```
+pub fn is_self_intersecting<T>(line_string: &LineString<T>) -> bool
+    where T: Float {
+    is_self_intersecting_exclusive(line_string)
+}
+
+/// Whether `line_string` crosses or touches itself, not counting the
+/// ordinary vertex shared by two consecutive segments as a
+/// self-intersection - only a genuine crossing or overlap does. This
+/// includes a closed ring's last segment meeting its first one back at the
+/// start point, which is just as ordinary a shared vertex as any other.
+/// [`is_self_intersecting`](fn.is_self_intersecting.html) is this same
+/// check under the plainer name.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Point, LineString};
+/// use geo::operation::sweep_line::is_self_intersecting_exclusive;
+///
+/// let simple = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 0.0)]);
+/// assert!(!is_self_intersecting_exclusive(&simple));
+///
+/// let ring = LineString(vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(2.0, 0.0),
+///     Point::new(2.0, 2.0),
+///     Point::new(0.0, 2.0),
+///     Point::new(0.0, 0.0),
+/// ]);
+/// assert!(!is_self_intersecting_exclusive(&ring));
+/// ```
+pub fn is_self_intersecting_exclusive<T>(line_string: &LineString<T>) -> bool
+    where T: Float {
+    let lines = segments(line_string);
+    let segment_count = lines.len();
+
+    // A closed ring's first and last segments legitimately share the
+    // closing vertex too, the same way any other pair of consecutive
+    // segments does - it just wraps around instead of sitting at adjacent
+    // indices.
+    let is_closed_ring = segment_count > 1 && {
+        match (line_string.0.first(), line_string.0.last()) {
+            (Some(first), Some(last)) => first.eq(last),
+            _ => false,
+        }
+    };
+
+    intersections(&lines).any(|(_, indices)| {
+        if indices.len() <= 1 {
+            return false;
+        }
+
+        if indices.len() == 2 {
+            let (a, b) = (indices[0].min(indices[1]), indices[0].max(indices[1]));
+            if b == a + 1 || (is_closed_ring && a == 0 && b == segment_count - 1) {
+                // adjacent segments always touch at their shared vertex;
+                // that's not a self-intersection, but doubling back along
+                // each other (a collinear overlap) is
+                return match line_intersection(&lines[a], &lines[b]) {
+                    Some(LineIntersection::Collinear { .. }) => true,
+                    _ => false,
+                };
+            }
+        }
+
+        true
+    })
+}